@@ -158,7 +158,7 @@ export function GameCard({ game, cardSize = "md", onEdit, onDelete, onClick, onL
                     invoke("open_folder", { path: game.save_path });
                   } else {
                     try {
-                      const dirs = await invoke<string[]>("find_save_directories", { installPath: game.install_path });
+                      const dirs = await invoke<string[]>("find_save_directories", { installPath: game.install_path, engine: game.engine || null });
                       invoke("open_folder", { path: dirs.length > 0 ? dirs[0] : game.install_path });
                     } catch {
                       invoke("open_folder", { path: game.install_path });
@@ -7,12 +7,12 @@ import type { DetectedGame, GameFormData } from "@/types/game";
 import { invoke } from "@tauri-apps/api/core";
 import { open } from "@tauri-apps/plugin-dialog";
 import {
-  searchVn, getVnById, extractTags, formatVndbDate,
+  searchVn, getVnById, rankVnResults, extractTags, formatVndbDate,
   cleanDescription, pickDisplayTitle, pickOriginalTitle, type VndbVn,
 } from "@/lib/vndb";
 import { translateDescription, matchGenreTags } from "@/lib/deepseek";
 import * as db from "@/lib/database";
-import { cn } from "@/lib/utils";
+import { cn, errorMessage } from "@/lib/utils";
 import { useToast } from "@/components/Toast";
 
 // ─── 类型 ────────────────────────────────────────────────────
@@ -39,8 +39,10 @@ interface Props {
 /**
  * 并行获取封面、截图、翻译简介、匹配标签。
  * 封面/截图走代理，DeepSeek 请求不走代理（由 Rust 端保证）。
+ * 文件名以 gameId 为前缀，避免不同游戏匹配到同一 VN 时互相覆盖。
  */
 async function fetchVnAssets(
+  gameId: string,
   vn: VndbVn,
   apiKey: string,
   proxyUrl: string,
@@ -56,11 +58,11 @@ async function fetchVnAssets(
       const ext = vn.image.url.split(".").pop() || "jpg";
       return await invoke<string>("download_cover", {
         url: vn.image.url,
-        filename: `${vn.id}.${ext}`,
+        filename: `${gameId}_${vn.id}.${ext}`,
         proxyUrl,
       });
     } catch (err) {
-      toast("error", `「${title}」封面下载失败: ${err}`);
+      toast("error", `「${title}」封面下载失败: ${errorMessage(err)}`);
       return "";
     }
   })();
@@ -72,7 +74,7 @@ async function fetchVnAssets(
     const settled = await Promise.allSettled(
       safe.map((ss) => {
         const ext = ss.url.split(".").pop() || "jpg";
-        return invoke<string>("download_screenshot", { url: ss.url, filename: `${ss.id}.${ext}`, proxyUrl });
+        return invoke<string>("download_screenshot", { url: ss.url, filename: `${gameId}_${ss.id}.${ext}`, proxyUrl });
       })
     );
     return settled
@@ -88,7 +90,7 @@ async function fetchVnAssets(
     try {
       return await translateDescription(cleaned, apiKey);
     } catch (err) {
-      toast("warning", `「${title}」简介翻译失败: ${err}`);
+      toast("warning", `「${title}」简介翻译失败: ${errorMessage(err)}`);
       return cleaned;
     }
   })();
@@ -142,9 +144,12 @@ export function ScanDialog({ onImport, onClose }: Props) {
     const sessionPaths = new Set(items.map((i) => i.detected.install_path));
     const dbPaths = await db.getExistingInstallPaths();
     let skippedCount = 0;
+    let unlaunchableCount = 0;
 
     const newItems: ImportItem[] = detected
       .filter((g) => {
+        // 补丁目录或未找到可执行文件的结果无法启动，不作为游戏导入
+        if (g.is_patch || !g.exe_path) { unlaunchableCount++; return false; }
         if (sessionPaths.has(g.install_path)) return false;
         if (dbPaths.has(g.install_path)) { skippedCount++; return false; }
         return true;
@@ -158,6 +163,7 @@ export function ScanDialog({ onImport, onClose }: Props) {
       }));
 
     if (skippedCount > 0) toast("warning", `已跳过 ${skippedCount} 个已导入的游戏`);
+    if (unlaunchableCount > 0) toast("warning", `已跳过 ${unlaunchableCount} 个补丁或无可执行文件的文件夹`);
     setItems((prev) => [...prev, ...newItems]);
   };
 
@@ -203,14 +209,16 @@ export function ScanDialog({ onImport, onClose }: Props) {
     // 单条处理：搜索 VNDB → 下载资源 → 更新状态
     const processItem = async (item: ImportItem, i: number): Promise<void> => {
       try {
-        const { results: candidates } = await searchVn(item.detected.title);
+        const { results } = await searchVn(item.detected.title);
+        // 按所有已知标题（含原名与别名）重新排序，避免文件夹用简称时选中错误条目
+        const candidates = await rankVnResults(item.detected.title, results);
         let vn: VndbVn | null = null;
         if (candidates.length > 0) {
           vn = (await getVnById(candidates[0].id)) ?? candidates[0];
         }
 
         const assets = vn
-          ? await fetchVnAssets(vn, apiKey, proxyUrl, genreTags, toast)
+          ? await fetchVnAssets(item.detected.id, vn, apiKey, proxyUrl, genreTags, toast)
           : { coverPath: "", screenshotPaths: [], translatedDesc: "", translatedTags: [] };
 
         setItems((prev) => prev.map((it, idx) =>
@@ -222,7 +230,7 @@ export function ScanDialog({ onImport, onClose }: Props) {
         ));
       } catch (err) {
         setItems((prev) => prev.map((it, idx) =>
-          idx === i ? { ...it, status: "failed", error: `匹配失败: ${err}` } : it
+          idx === i ? { ...it, status: "failed", error: `匹配失败: ${errorMessage(err)}` } : it
         ));
       }
     };
@@ -304,7 +312,7 @@ export function ScanDialog({ onImport, onClose }: Props) {
         db.getGenreTags(),
       ]);
 
-      const assets = await fetchVnAssets(fullVn, apiKey, proxyUrl, genreTags, toast);
+      const assets = await fetchVnAssets(items[targetIdx].detected.id, fullVn, apiKey, proxyUrl, genreTags, toast);
 
       setItems((prev) => prev.map((it, idx) =>
         idx === targetIdx
@@ -313,7 +321,7 @@ export function ScanDialog({ onImport, onClose }: Props) {
       ));
     } catch (err) {
       setItems((prev) => prev.map((it, idx) =>
-        idx === targetIdx ? { ...it, status: "failed", error: `处理失败: ${err}` } : it
+        idx === targetIdx ? { ...it, status: "failed", error: `处理失败: ${errorMessage(err)}` } : it
       ));
     }
   };
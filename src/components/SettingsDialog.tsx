@@ -3,13 +3,21 @@ import { X, Key, Loader2, CheckCircle2, AlertCircle, Tag, Plus, Palette, Check,
 import { invoke } from "@tauri-apps/api/core";
 import { listen } from "@tauri-apps/api/event";
 import * as db from "@/lib/database";
-import { testApiKey } from "@/lib/deepseek";
+import { errorMessage } from "@/lib/utils";
+import { testApiKey, type ApiKeyTestResult } from "@/lib/deepseek";
 import { useTheme, THEME_LIST } from "@/hooks/useTheme";
 import { useAppearance, CARD_SIZE_OPTIONS, CARD_GAP_OPTIONS } from "@/hooks/useAppearance";
 import { MODEL_LIST } from "@/components/Live2DWidget/models";
 
 const GITHUB_REPO = "Chty-syq/GalAirport";
 
+const KEY_TEST_FAILURE_TEXT: Record<NonNullable<ApiKeyTestResult["reason"]>, string> = {
+  invalid_key: "Key 无效，请检查 Key",
+  network: "网络连接失败，请检查网络或代理",
+  rate_limited: "请求过于频繁，请稍后再试",
+  unknown: "连接失败",
+};
+
 interface Props {
   onClose: () => void;
   onLive2dChange?: (enabled: boolean) => void;
@@ -36,7 +44,7 @@ export function SettingsDialog({ onClose, initialTab, onLive2dChange, onLive2dHe
   const [proxyUrl, setProxyUrl] = useState("");
   const [loading, setLoading] = useState(true);
   const [testing, setTesting] = useState(false);
-  const [testResult, setTestResult] = useState<"success" | "fail" | null>(null);
+  const [testResult, setTestResult] = useState<ApiKeyTestResult | null>(null);
   const [testingVndb, setTestingVndb] = useState(false);
   const [vndbTestResult, setVndbTestResult] = useState<"success" | "fail" | null>(null);
   const [vndbLatency, setVndbLatency] = useState<number | null>(null);
@@ -127,8 +135,7 @@ export function SettingsDialog({ onClose, initialTab, onLive2dChange, onLive2dHe
   const handleTest = async () => {
     setTesting(true);
     setTestResult(null);
-    const ok = await testApiKey(deepseekKey);
-    setTestResult(ok ? "success" : "fail");
+    setTestResult(await testApiKey(deepseekKey));
     setTesting(false);
   };
 
@@ -151,7 +158,7 @@ export function SettingsDialog({ onClose, initialTab, onLive2dChange, onLive2dHe
       });
       setDownloadedPath(path);
     } catch (e) {
-      setDownloadError(String(e));
+      setDownloadError(errorMessage(e));
     } finally {
       unlisten();
       setDownloading(false);
@@ -171,7 +178,7 @@ export function SettingsDialog({ onClose, initialTab, onLive2dChange, onLive2dHe
       const info = await invoke<UpdateInfo>("check_update", { proxyUrl: proxyUrl.trim() });
       setUpdateInfo(info);
     } catch (e) {
-      setUpdateError(String(e));
+      setUpdateError(errorMessage(e));
     }
     setCheckingUpdate(false);
   };
@@ -187,7 +194,7 @@ export function SettingsDialog({ onClose, initialTab, onLive2dChange, onLive2dHe
       setVndbTestResult("success");
     } catch (e) {
       setVndbTestResult("fail");
-      setVndbTestError(String(e));
+      setVndbTestError(errorMessage(e));
     }
     setTestingVndb(false);
   };
@@ -453,14 +460,17 @@ export function SettingsDialog({ onClose, initialTab, onLive2dChange, onLive2dHe
                       >
                         {testing ? <Loader2 className="w-3 h-3 animate-spin" /> : "测试连接"}
                       </button>
-                      {testResult === "success" && (
+                      {testResult?.ok && (
                         <span className="text-[10px] text-status-finished flex items-center gap-1">
                           <CheckCircle2 className="w-3 h-3" /> 连接成功
                         </span>
                       )}
-                      {testResult === "fail" && (
-                        <span className="text-[10px] text-status-shelved flex items-center gap-1">
-                          <AlertCircle className="w-3 h-3" /> 连接失败，请检查 Key
+                      {testResult && !testResult.ok && (
+                        <span
+                          className="text-[10px] text-status-shelved flex items-center gap-1"
+                          title={testResult.message}
+                        >
+                          <AlertCircle className="w-3 h-3" /> {KEY_TEST_FAILURE_TEXT[testResult.reason ?? "unknown"]}
                         </span>
                       )}
                     </div>
@@ -29,7 +29,7 @@ import {
 } from "@/lib/vndb";
 import { translateDescription, matchGenreTags } from "@/lib/deepseek";
 import * as database from "@/lib/database";
-import { cn } from "@/lib/utils";
+import { cn, errorMessage } from "@/lib/utils";
 import { useToast } from "@/components/Toast";
 
 interface Props {
@@ -87,7 +87,7 @@ export function VndbMatchDialog({ game, onClose, onApply }: Props) {
         setError("未找到匹配结果，请尝试日文/英文标题");
       }
     } catch (err) {
-      setError(`搜索失败: ${err}`);
+      setError(`搜索失败: ${errorMessage(err)}`);
       setResults([]);
     } finally {
       setSearching(false);
@@ -151,11 +151,11 @@ export function VndbMatchDialog({ game, onClose, onApply }: Props) {
             const ext = selectedCover.url.split(".").pop() || "jpg";
             return await invoke<string>("download_cover", {
               url: selectedCover.url,
-              filename: `${selectedCover.id}.${ext}`,
+              filename: `${game.id}_${selectedCover.id}.${ext}`,
               proxyUrl,
             });
           } catch (err) {
-            toast("error", `「${selectedVn.title}」封面下载失败: ${err}`);
+            toast("error", `「${selectedVn.title}」封面下载失败: ${errorMessage(err)}`);
           }
         }
         return game.cover_path;
@@ -166,7 +166,7 @@ export function VndbMatchDialog({ game, onClose, onApply }: Props) {
         const results = await Promise.allSettled(
           selectedVn.screenshots.slice(0, 6).map((ss) => {
             const ext = ss.url.split(".").pop() || "jpg";
-            return invoke<string>("download_screenshot", { url: ss.url, filename: `${ss.id}.${ext}`, proxyUrl });
+            return invoke<string>("download_screenshot", { url: ss.url, filename: `${game.id}_${ss.id}.${ext}`, proxyUrl });
           })
         );
         return results
@@ -181,7 +181,7 @@ export function VndbMatchDialog({ game, onClose, onApply }: Props) {
           try {
             return await translateDescription(cleaned, apiKey);
           } catch (err) {
-            toast("warning", `简介翻译失败: ${err}`);
+            toast("warning", `简介翻译失败: ${errorMessage(err)}`);
             return cleaned;
           }
         }
@@ -223,7 +223,7 @@ export function VndbMatchDialog({ game, onClose, onApply }: Props) {
 
       onApply(update);
     } catch (err) {
-      setError(`应用元数据失败: ${err}`);
+      setError(`应用元数据失败: ${errorMessage(err)}`);
     } finally {
       setApplying(false);
     }
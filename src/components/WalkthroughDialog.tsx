@@ -4,6 +4,7 @@ import { X, Edit, Wand2, Loader2, Save, AlertCircle, GitBranch, ArrowLeft, GitCo
 import { invoke } from "@tauri-apps/api/core";
 import type { Game } from "@/types/game";
 import * as db from "@/lib/database";
+import { errorMessage } from "@/lib/utils";
 
 // ─── Unified flowchart style (theme-independent) ─────────────
 // Node semantics (Mermaid shape → SVG element):
@@ -198,7 +199,7 @@ export function WalkthroughDialog({ game, onClose }: Props) {
       setCode(result);
       setLeftPanel("code"); // switch to code view to show result
     } catch (e) {
-      setGenError(String(e));
+      setGenError(errorMessage(e));
     }
     setGenerating(false);
   };
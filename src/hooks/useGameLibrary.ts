@@ -1,7 +1,13 @@
 import { useState, useEffect, useCallback, useMemo } from "react";
 import type { Collection, Game, GameFormData, PlayStatus, SortField, SortDirection } from "@/types/game";
+import { invoke } from "@tauri-apps/api/core";
 import * as db from "@/lib/database";
 
+/** 清理游戏关联的封面/截图缓存，失败不影响删除流程 */
+async function cleanupMedia(ids: string[]) {
+  await Promise.allSettled(ids.map((gameId) => invoke("cleanup_game_media", { gameId })));
+}
+
 export function useGameLibrary() {
   const [games, setGames] = useState<Game[]>([]);
   const [loading, setLoading] = useState(true);
@@ -63,6 +69,7 @@ export function useGameLibrary() {
   const deleteGame = useCallback(
     async (id: string) => {
       await db.deleteGame(id);
+      await cleanupMedia([id]);
       await loadGames();
     },
     [loadGames]
@@ -71,6 +78,7 @@ export function useGameLibrary() {
   const deleteGames = useCallback(
     async (ids: string[]) => {
       await db.deleteGames(ids);
+      await cleanupMedia(ids);
       await loadGames();
     },
     [loadGames]
@@ -5,13 +5,23 @@
 
 import { invoke } from "@tauri-apps/api/core";
 
+/**
+ * Optional OpenAI-compatible endpoint override (OpenAI, Moonshot, Ollama, LM Studio...).
+ * Empty / missing values fall back to DeepSeek on the Rust side.
+ */
+export interface LlmEndpoint {
+  apiBase?: string;
+  model?: string;
+}
+
 /**
  * Translate a VN description to Chinese using DeepSeek via async-openai.
  * Caller is responsible for cleaning the text before passing it in.
  */
 export async function translateDescription(
   text: string,
-  apiKey: string
+  apiKey: string,
+  endpoint: LlmEndpoint = {}
 ): Promise<string> {
   if (!text.trim() || !apiKey.trim()) return text;
 
@@ -21,19 +31,30 @@ export async function translateDescription(
   const result = await invoke<string>("deepseek_translate", {
     apiKey,
     text: truncated,
+    ...endpoint,
   });
 
   return result;
 }
 
+export interface ApiKeyTestResult {
+  ok: boolean;
+  reason: "invalid_key" | "network" | "rate_limited" | "unknown" | null;
+  message: string;
+}
+
 /**
  * Check if an API key is valid by making a minimal test request.
+ * On failure `reason` tells an invalid key apart from network / proxy problems and rate limiting.
  */
-export async function testApiKey(apiKey: string): Promise<boolean> {
+export async function testApiKey(
+  apiKey: string,
+  endpoint: LlmEndpoint = {}
+): Promise<ApiKeyTestResult> {
   try {
-    return await invoke<boolean>("deepseek_test", { apiKey });
-  } catch {
-    return false;
+    return await invoke<ApiKeyTestResult>("deepseek_test", { apiKey, ...endpoint });
+  } catch (e) {
+    return { ok: false, reason: "unknown", message: String(e) };
   }
 }
 
@@ -44,7 +65,8 @@ export async function testApiKey(apiKey: string): Promise<boolean> {
 export async function matchGenreTags(
   vndbTags: string[],
   genreTags: string[],
-  apiKey: string
+  apiKey: string,
+  endpoint: LlmEndpoint = {}
 ): Promise<string[]> {
   if (!vndbTags.length || !genreTags.length || !apiKey.trim()) return [];
   try {
@@ -52,6 +74,7 @@ export async function matchGenreTags(
       apiKey,
       vndbTags,
       genreTags,
+      ...endpoint,
     });
   } catch {
     return [];
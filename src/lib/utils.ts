@@ -14,6 +14,18 @@ export function coverSrc(path: string | null | undefined): string {
   return convertFileSrc(path);
 }
 
+/**
+ * Extract a readable message from a rejected invoke / thrown value.
+ * Rust commands reject with `{ kind, message }` (CommandError).
+ */
+export function errorMessage(err: unknown): string {
+  if (err instanceof Error) return err.message;
+  if (err && typeof err === "object" && typeof (err as { message?: unknown }).message === "string") {
+    return (err as { message: string }).message;
+  }
+  return String(err);
+}
+
 export function formatPlaytime(seconds: number): string {
   if (seconds < 60) return "< 1 分钟";
   const hours = Math.floor(seconds / 3600);
@@ -4,6 +4,8 @@
  * No API key required for read-only queries.
  */
 
+import { invoke } from "@tauri-apps/api/core";
+
 const VNDB_API = "https://api.vndb.org/kana";
 
 // ─── Types ─────────────────────────────────────────────────────
@@ -21,6 +23,8 @@ export interface VndbVn {
   title: string;               // Default display title (romanized)
   alttitle: string | null;     // Alt title (original script)
   titles: VndbTitle[];         // All titles across languages
+  aliases: string[];           // Unofficial aliases / abbreviations
+  original_title: string;      // Derived via pickOriginalTitle, filled in by searchVn / getVnById
   released: string | null;
   image: VndbImage | null;
   screenshots: VndbImage[];    // game screenshots from VNDB
@@ -76,6 +80,7 @@ const VN_FIELDS = [
   "titles.latin",
   "titles.official",
   "titles.main",
+  "aliases",
   "released",
   "image.id",
   "image.url",
@@ -125,12 +130,13 @@ export async function searchVn(query: string, page = 1): Promise<VndbSearchResul
 
   const data = await resp.json();
 
-  const results: VndbVn[] = (data.results ?? []).map((vn: Record<string, unknown>) => ({
+  const results: VndbVn[] = (data.results ?? []).map((vn: Record<string, unknown>) => withOriginalTitle({
     ...vn,
     titles: vn.titles ?? [],
+    aliases: vn.aliases ?? [],
     screenshots: vn.screenshots ?? [],
     developers: [],
-  }));
+  } as VndbVn));
 
   return { results, more: data.more ?? false, count: data.count };
 }
@@ -156,7 +162,13 @@ export async function getVnById(vnId: string): Promise<VndbVn | null> {
   const vn = data.results[0];
   const developers = await fetchDevelopers(vnId);
 
-  return { ...vn, titles: vn.titles ?? [], screenshots: vn.screenshots ?? [], developers };
+  return withOriginalTitle({
+    ...vn,
+    titles: vn.titles ?? [],
+    aliases: vn.aliases ?? [],
+    screenshots: vn.screenshots ?? [],
+    developers,
+  });
 }
 
 async function fetchDevelopers(vnId: string): Promise<VndbProducer[]> {
@@ -264,6 +276,20 @@ export function pickOriginalTitle(vn: VndbVn): string {
   return "";
 }
 
+function withOriginalTitle(vn: VndbVn): VndbVn {
+  return { ...vn, original_title: pickOriginalTitle(vn) };
+}
+
+/**
+ * Re-rank search results by similarity to a folder title. The Rust side compares
+ * against every known title (original title, per-language titles and aliases),
+ * so folders named by abbreviation still pick the right VN.
+ */
+export async function rankVnResults(title: string, results: VndbVn[]): Promise<VndbVn[]> {
+  if (results.length < 2) return results;
+  return invoke<VndbVn[]>("rank_vndb_results", { title, results });
+}
+
 // ─── Other Utilities ───────────────────────────────────────────
 
 export function extractTags(tags: VndbTag[], maxSpoiler = 0, maxCount = 8): string[] {
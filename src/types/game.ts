@@ -33,10 +33,26 @@ export interface Game {
 }
 
 export interface DetectedGame {
-  title: string;
+  id: string;                  // stable id from engine + folder name + exe path/size; may collide, not unique
+  title: string;               // cleaned folder name
+  raw_title: string;           // original folder name
   exe_path: string;
   install_path: string;
   engine: string | null;
+  install_size: number;        // total bytes under install_path
+  all_executables: ExeCandidate[]; // non-blacklisted candidates, best first
+  language?: string | null;    // guessed language of the main exe
+  is_patch?: boolean;          // standalone patch folder rather than a full game
+  archive_count?: number;      // engine archives (data.xp3, data2.xp3, ...)
+  has_patch_archive?: boolean; // patch*.xp3 etc. present, usually a translation
+  requires_disc?: boolean;     // disc image found in the folder, may need mounting before launch
+  disc_image?: string | null;  // preferred image (.iso > .mds > .cue > .mdf)
+}
+
+export interface ExeCandidate {
+  path: string;
+  score: number;
+  label: "main" | "tool" | "patch";
 }
 
 export interface GameFormData {